@@ -5,7 +5,7 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType},
     ExecutableCommand, QueueableCommand,
 };
-use rand::{thread_rng, Rng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{
     cmp::Ordering::*,
     io::{stdout, Stdout, Write},
@@ -13,6 +13,37 @@ use std::{
     time::{self, Duration},
 };
 
+// how far below an enemy the player must be before it notices and starts hunting
+const ENEMY_SIGHT_DISTANCE: u16 = 10;
+// how many ticks an enemy keeps moving straight down after a hunt step was blocked by a wall
+const ENEMY_HUNT_TIMEOUT: u16 = 5;
+// ticks between shots once an enemy is aligned with the player
+const ENEMY_ARROW_COOLDOWN: u16 = 20;
+// how far an enemy must be to either side of the player's column to still count as "aligned"
+const ENEMY_ARROW_ALIGNMENT: u16 = 1;
+// rows an enemy projectile advances per tick
+const ENEMY_BULLET_SPEED: u16 = 1;
+
+const PLAYER_MAX_HEALTH: u16 = 100;
+// health lost per enemy or enemy-projectile collision
+const TOUCH_DAMAGE: u16 = 25;
+// columns the player is pushed away from the point of impact
+const KNOCKBACK: u16 = 2;
+// ticks of post-hit invulnerability, during which the ship blinks
+const HURT_FREEZE: u16 = 10;
+
+const FUEL_MAX: u16 = 100;
+// ticks between each drop in fuel
+const FUEL_DRAIN_INTERVAL: u16 = 5;
+const FUEL_DRAIN_AMOUNT: u16 = 1;
+// width, in cells, of a spawned fuel depot
+const FUEL_DEPOT_WIDTH: u16 = 3;
+// minimum ticks between fuel depot spawns
+const FUEL_DEPOT_COOLDOWN: u16 = 40;
+
+// total ticks the player death animation plays before the game transitions to Dead
+const DEATH_ANIMATION_TICKS: u16 = 8;
+
 #[derive(PartialEq, Eq)]
 enum PlayerStatus {
     Dead,
@@ -35,8 +66,32 @@ impl Location {
     }
 }
 
+#[derive(PartialEq, Eq)]
+enum EnemyState {
+    Idle,
+    Hunt,
+}
+
 struct Enemy {
     location: Location,
+    // column this enemy was steering from before its last hunt step, kept even
+    // once it stops moving so a later touch/shot still knows which side it came from
+    prev_c: u16,
+    state: EnemyState,
+    hunt_timeout: u16,
+    shoot_cooldown: u16,
+}
+
+impl Enemy {
+    fn new(location: Location) -> Enemy {
+        Enemy {
+            prev_c: location.c,
+            location,
+            state: EnemyState::Idle,
+            hunt_timeout: 0,
+            shoot_cooldown: ENEMY_ARROW_COOLDOWN,
+        }
+    }
 }
 
 struct Bullet {
@@ -53,24 +108,106 @@ impl Bullet {
     }
 }
 
+struct EnemyBullet {
+    location: Location,
+    // column the firing enemy was approaching from, so a hit knocks the
+    // player away from that side instead of the bullet's own (aligned) column
+    source_c: u16,
+}
+
+impl EnemyBullet {
+    fn new(location: Location, source_c: u16) -> EnemyBullet {
+        EnemyBullet { location, source_c }
+    }
+}
+
+struct AnimationFrame {
+    glyph: String,
+    duration: u16,
+}
+
+struct Animation {
+    frames: Vec<AnimationFrame>,
+    frame: usize,
+    timer: u16,
+}
+
+impl Animation {
+    fn new(frames: Vec<AnimationFrame>) -> Animation {
+        let timer = frames[0].duration;
+        Animation {
+            frames,
+            frame: 0,
+            timer,
+        }
+    }
+
+    fn explosion() -> Animation {
+        Animation::new(vec![
+            AnimationFrame {
+                glyph: "*".to_string(),
+                duration: 3,
+            },
+            AnimationFrame {
+                glyph: "X".to_string(),
+                duration: 3,
+            },
+            AnimationFrame {
+                glyph: ".".to_string(),
+                duration: 2,
+            },
+        ])
+    }
+
+    fn glyph(&self) -> &str {
+        &self.frames[self.frame].glyph
+    }
+
+    // advances the frame timer, returns true once the last frame has finished
+    fn tick(&mut self) -> bool {
+        self.timer = self.timer.saturating_sub(1);
+        if self.timer > 0 {
+            return false;
+        }
+        self.frame += 1;
+        if self.frame >= self.frames.len() {
+            return true;
+        }
+        self.timer = self.frames[self.frame].duration;
+        false
+    }
+}
+
 struct World {
     player_location: Location,
     maxc: u16,
     maxl: u16,
     status: PlayerStatus,
     map: Vec<(u16, u16)>,
+    // fuel depot spanning (left, right) on the matching `map` row, if any
+    depot: Vec<Option<(u16, u16)>>,
     next_right: u16,
     next_left: u16,
     ship: String,
     enemy: Vec<Enemy>,
     bullet: Vec<Bullet>,
+    enemy_bullet: Vec<EnemyBullet>,
+    health: u16,
+    hurt_freeze: u16,
+    fuel: u16,
+    fuel_tick: u16,
+    depot_cooldown: u16,
+    animations: Vec<(Location, Animation)>,
+    death_timer: Option<u16>,
+    rng: StdRng,
 }
 
 impl World {
-    fn new(maxc: u16, maxl: u16) -> World {
+    fn new(maxc: u16, maxl: u16, seed: u64) -> World {
         World {
             player_location: Location::new(maxl - 1, maxc / 2),
             map: vec![(maxc / 2 - 5, maxc / 2 + 5); maxl as usize],
+            depot: vec![None; maxl as usize],
             maxc,
             maxl,
             status: PlayerStatus::Alive,
@@ -79,11 +216,28 @@ impl World {
             ship: "P".to_string(),
             enemy: vec![],
             bullet: vec![],
+            enemy_bullet: vec![],
+            health: PLAYER_MAX_HEALTH,
+            hurt_freeze: 0,
+            fuel: FUEL_MAX,
+            fuel_tick: 0,
+            depot_cooldown: 0,
+            animations: vec![],
+            death_timer: None,
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 }
 
 fn draw(mut sc: &Stdout, world: &World) -> std::io::Result<()> {
+    // while paused, leave the last frame on screen and just overlay the banner
+    if world.status == PlayerStatus::Paused {
+        sc.queue(MoveTo(world.maxc / 2 - 3, world.maxl / 2))?
+            .queue(Print("PAUSED"))?
+            .flush()?;
+        return Ok(());
+    }
+
     sc.queue(Clear(ClearType::All))?;
 
     // draw the map
@@ -92,6 +246,11 @@ fn draw(mut sc: &Stdout, world: &World) -> std::io::Result<()> {
             .queue(Print("*".repeat(world.map[l].0 as usize)))?
             .queue(MoveTo(world.map[l].1, l as u16))?
             .queue(Print("*".repeat((world.maxc - world.map[l].1) as usize)))?;
+
+        if let Some((left, right)) = world.depot[l] {
+            sc.queue(MoveTo(left, l as u16))?
+                .queue(Print("F".repeat((right - left) as usize)))?;
+        }
     }
 
     // draw the enemies
@@ -108,22 +267,100 @@ fn draw(mut sc: &Stdout, world: &World) -> std::io::Result<()> {
             .flush()?;
     }
 
-    // draw the player
-    sc.queue(MoveTo(world.player_location.c, world.player_location.l))?
-        .queue(Print(world.ship.as_str()))?
+    // draw enemy bullets
+    for enemy_bullet in &world.enemy_bullet {
+        sc.queue(MoveTo(enemy_bullet.location.c, enemy_bullet.location.l))?
+            .queue(Print("v"))?
+            .flush()?;
+    }
+
+    // draw animations (explosions and the like)
+    for (location, animation) in &world.animations {
+        sc.queue(MoveTo(location.c, location.l))?
+            .queue(Print(animation.glyph()))?
+            .flush()?;
+    }
+
+    // draw the player, blinking it off every other tick while invulnerable, and
+    // hidden entirely while the death explosion plays so it isn't painted over it
+    if world.status != PlayerStatus::Animation && world.hurt_freeze.is_multiple_of(2) {
+        sc.queue(MoveTo(world.player_location.c, world.player_location.l))?
+            .queue(Print(world.ship.as_str()))?
+            .flush()?;
+    }
+
+    // HUD
+    sc.queue(MoveTo(0, 0))?
+        .queue(Print(format!("HP: {}  Fuel: [{}]", world.health, "#".repeat((world.fuel / 10) as usize))))?
         .flush()?;
 
     Ok(())
 }
 
-fn physics(world: &mut World) {
-    let mut rng = thread_rng();
+// plays the death explosion at the player's location and holds `status` at
+// `Animation` until it has finished, at which point physics() moves on to `Dead`
+fn kill_player(world: &mut World) {
+    if world.death_timer.is_some() {
+        return;
+    }
+    world.animations.push((
+        Location::new(world.player_location.l, world.player_location.c),
+        Animation::explosion(),
+    ));
+    world.status = PlayerStatus::Animation;
+    world.death_timer = Some(DEATH_ANIMATION_TICKS);
+}
+
+// apply touch damage from a threat that approached from column `impact_c`, knocking the
+// player away from that side; ignores the hit while still within the post-hit
+// invulnerability window
+fn apply_touch_damage(world: &mut World, impact_c: u16) {
+    if world.hurt_freeze > 0 {
+        return;
+    }
+
+    world.health = world.health.saturating_sub(TOUCH_DAMAGE);
+    world.hurt_freeze = HURT_FREEZE;
+
+    if world.player_location.c >= impact_c {
+        world.player_location.c = (world.player_location.c + KNOCKBACK).min(world.maxc - 1);
+    } else {
+        world.player_location.c = world.player_location.c.saturating_sub(KNOCKBACK).max(1);
+    }
 
+    if world.health == 0 {
+        kill_player(world);
+    }
+}
+
+fn physics(world: &mut World) {
     // check if player has hit the ground
     if world.player_location.c <= world.map[world.player_location.l as usize].0
         || world.player_location.c >= world.map[world.player_location.l as usize].1
     {
-        world.status = PlayerStatus::Dead
+        kill_player(world)
+    }
+
+    // tick down the post-hit invulnerability window
+    if world.hurt_freeze > 0 {
+        world.hurt_freeze -= 1;
+    }
+
+    // advance animations, dropping any that have played their last frame
+    for i in (0..world.animations.len()).rev() {
+        if world.animations[i].1.tick() {
+            world.animations.remove(i);
+        }
+    }
+
+    // once the player death animation has played out, hand off to Dead
+    if let Some(t) = world.death_timer {
+        if t == 0 {
+            world.status = PlayerStatus::Dead;
+            world.death_timer = None;
+        } else {
+            world.death_timer = Some(t - 1);
+        }
     }
 
     // check if player has hit an enemy or bullet has hit an enemy
@@ -131,7 +368,7 @@ fn physics(world: &mut World) {
         if world.enemy[i].location.c == world.player_location.c
             && world.enemy[i].location.l == world.player_location.l
         {
-            world.status = PlayerStatus::Dead
+            apply_touch_damage(world, world.enemy[i].prev_c);
         }
         for bullet in &world.bullet {
             if bullet.location.hit(&world.enemy[i].location)
@@ -140,7 +377,28 @@ fn physics(world: &mut World) {
                     world.enemy[i].location.c,
                 ))
             {
+                world.animations.push((
+                    Location::new(world.enemy[i].location.l, world.enemy[i].location.c),
+                    Animation::explosion(),
+                ));
                 world.enemy.remove(i);
+                break;
+            }
+        }
+    }
+
+    // check if player has been hit by an enemy bullet, and if the player's bullet
+    // has shot one down first
+    for i in (0..world.enemy_bullet.len()).rev() {
+        if world.enemy_bullet[i].location.hit(&world.player_location) {
+            apply_touch_damage(world, world.enemy_bullet[i].source_c);
+            world.enemy_bullet.remove(i);
+            continue;
+        }
+        for bullet in &world.bullet {
+            if bullet.location.hit(&world.enemy_bullet[i].location) {
+                world.enemy_bullet.remove(i);
+                break;
             }
         }
     }
@@ -163,32 +421,138 @@ fn physics(world: &mut World) {
         Equal => {}
     };
 
-    // TODO : below rands may go out of range
-    if world.next_left == world.map[0].0 && rng.gen_range(0..10) >= 7 {
-        world.next_left = rng.gen_range(world.next_left - 5..world.next_left + 5)
+    // clamp the +/-5 wander to stay inside the screen so a narrow river can't
+    // underflow `- 5` or pick a wall past `maxc`
+    if world.next_left == world.map[0].0 && world.rng.gen_range(0..10) >= 7 {
+        let low = world.next_left.saturating_sub(5).max(1);
+        let high = (world.next_left + 5).min(world.maxc - 1);
+        world.next_left = world.rng.gen_range(low..=high)
     }
-    if world.next_right == world.map[0].1 && rng.gen_range(0..10) >= 7 {
-        world.next_right = rng.gen_range(world.next_right - 5..world.next_right + 5)
+    if world.next_right == world.map[0].1 && world.rng.gen_range(0..10) >= 7 {
+        let low = world.next_right.saturating_sub(5).max(1);
+        let high = (world.next_right + 5).min(world.maxc - 1);
+        world.next_right = world.rng.gen_range(low..=high)
     }
 
     if world.next_right.abs_diff(world.next_left) < 3 {
         world.next_right += 3;
     }
 
+    // move fuel depots downward alongside the map, spawning a new one at the top
+    // every so often once the cooldown has run out
+    for l in (1..world.depot.len()).rev() {
+        world.depot[l] = world.depot[l - 1]
+    }
+
+    let (left, right) = world.map[0];
+    // use a checked width instead of `right > left + 1 + FUEL_DEPOT_WIDTH` so a
+    // narrowed-to-nothing river can't overflow the addition and pass spuriously
+    let fits_depot = right
+        .checked_sub(left)
+        .is_some_and(|width| width > 1 + FUEL_DEPOT_WIDTH);
+    if world.depot_cooldown > 0 {
+        world.depot_cooldown -= 1;
+        world.depot[0] = None;
+    } else if fits_depot && world.rng.gen_range(0..10) >= 8 {
+        let start = world.rng.gen_range(left + 1..right - FUEL_DEPOT_WIDTH);
+        world.depot[0] = Some((start, start + FUEL_DEPOT_WIDTH));
+        world.depot_cooldown = FUEL_DEPOT_COOLDOWN;
+    } else {
+        world.depot[0] = None;
+    }
+
+    // drain fuel over time, and refill it when the player flies over a depot
+    world.fuel_tick += 1;
+    if world.fuel_tick >= FUEL_DRAIN_INTERVAL {
+        world.fuel_tick = 0;
+        world.fuel = world.fuel.saturating_sub(FUEL_DRAIN_AMOUNT);
+        if world.fuel == 0 {
+            kill_player(world);
+        }
+    }
+
+    if let Some((left, right)) = world.depot[world.player_location.l as usize] {
+        if world.player_location.c >= left && world.player_location.c < right {
+            world.fuel = FUEL_MAX;
+        }
+    }
+
     // move and spawn enemies
     for i in (0..world.enemy.len()).rev() {
-        if world.enemy[i].location.l < world.maxl {
-            world.enemy[i].location.l += 1;
-        } else {
+        if world.enemy[i].location.l >= world.maxl {
             world.enemy.remove(i);
+            continue;
+        }
+
+        let l = world.enemy[i].location.l as usize;
+        let (left, right) = world.map[l];
+        let in_river = world.player_location.c > left && world.player_location.c < right;
+        let in_sight = world.player_location.l > world.enemy[i].location.l
+            && world.player_location.l - world.enemy[i].location.l <= ENEMY_SIGHT_DISTANCE;
+
+        if in_sight && in_river {
+            world.enemy[i].state = EnemyState::Hunt;
+        }
+
+        if world.enemy[i].state == EnemyState::Hunt {
+            if world.enemy[i].hunt_timeout > 0 {
+                world.enemy[i].hunt_timeout -= 1;
+            } else if right <= left + 2 {
+                // river too narrow to steer within right now; fall back to straight down
+                world.enemy[i].hunt_timeout = ENEMY_HUNT_TIMEOUT;
+            } else {
+                let desired_c = world.player_location.c.clamp(left + 1, right - 1);
+                let step = match desired_c.cmp(&world.enemy[i].location.c) {
+                    Greater => 1,
+                    Less => -1,
+                    Equal => 0,
+                };
+                let next_c = world.enemy[i].location.c as i32 + step;
+                if next_c > left as i32 && next_c < right as i32 {
+                    world.enemy[i].prev_c = world.enemy[i].location.c;
+                    world.enemy[i].location.c = next_c as u16;
+                } else {
+                    world.enemy[i].hunt_timeout = ENEMY_HUNT_TIMEOUT;
+                }
+            }
+        }
+
+        // shoot at the player once aligned and off cooldown
+        let aligned = world.enemy[i].location.l <= world.player_location.l
+            && world
+                .enemy[i]
+                .location
+                .c
+                .abs_diff(world.player_location.c)
+                <= ENEMY_ARROW_ALIGNMENT;
+        if world.enemy[i].shoot_cooldown > 0 {
+            world.enemy[i].shoot_cooldown -= 1;
+        } else if aligned {
+            world.enemy_bullet.push(EnemyBullet::new(
+                Location::new(world.enemy[i].location.l, world.enemy[i].location.c),
+                world.enemy[i].prev_c,
+            ));
+            world.enemy[i].shoot_cooldown = ENEMY_ARROW_COOLDOWN;
+        }
+
+        world.enemy[i].location.l += 1;
+    }
+
+    // move enemy bullets and remove once they would move past the bottom of the screen
+    for i in (0..world.enemy_bullet.len()).rev() {
+        if world.enemy_bullet[i].location.l + ENEMY_BULLET_SPEED >= world.maxl {
+            world.enemy_bullet.remove(i);
+            continue;
         }
+        world.enemy_bullet[i].location.l += ENEMY_BULLET_SPEED;
     }
 
-    if rng.gen_range(0..10) >= 9 {
-        let new_c = rng.gen_range(world.map[0].0..world.map[1].1);
-        world.enemy.push(Enemy {
-            location: Location::new(0, new_c),
-        })
+    // the river can narrow unevenly between adjacent rows, so map[0].0..map[1].1
+    // isn't guaranteed to be a valid (non-empty) range; skip the spawn that tick instead
+    let spawn_range = world.map[0].0.max(world.map[1].0)..world.map[0].1.min(world.map[1].1);
+    if !spawn_range.is_empty() && world.rng.gen_range(0..10) >= 9 {
+        let new_c = world.rng.gen_range(spawn_range);
+        world.enemy.push(Enemy::new(Location::new(0, new_c)))
     }
 
     // move bullets and remove once collided or out of screen
@@ -205,6 +569,68 @@ fn physics(world: &mut World) {
     }
 }
 
+// recorded/replayed as one token per tick: "-" for no key, a single char for
+// letters and space, or the arrow key's name
+fn key_code_to_token(code: KeyCode) -> Option<String> {
+    match code {
+        KeyCode::Char(c) => Some(c.to_string()),
+        KeyCode::Up => Some("Up".to_string()),
+        KeyCode::Down => Some("Down".to_string()),
+        KeyCode::Left => Some("Left".to_string()),
+        KeyCode::Right => Some("Right".to_string()),
+        _ => None,
+    }
+}
+
+fn token_to_key_code(token: &str) -> Option<KeyCode> {
+    match token {
+        "Up" => Some(KeyCode::Up),
+        "Down" => Some(KeyCode::Down),
+        "Left" => Some(KeyCode::Left),
+        "Right" => Some(KeyCode::Right),
+        "-" => None,
+        s => s.chars().next().map(KeyCode::Char),
+    }
+}
+
+// CLI flags: `--seed <n>` pins the RNG seed, `--record <path>` writes the seed plus
+// every tick's key to a replay file, and `--replay <path>` re-drives the main loop
+// from a previously recorded file instead of reading real input.
+struct Args {
+    seed: Option<u64>,
+    record: Option<String>,
+    replay: Option<String>,
+}
+
+fn parse_args() -> Args {
+    let argv: Vec<String> = std::env::args().collect();
+    let mut args = Args {
+        seed: None,
+        record: None,
+        replay: None,
+    };
+    let mut i = 1;
+    while i < argv.len() {
+        match argv[i].as_str() {
+            "--seed" => {
+                i += 1;
+                args.seed = argv.get(i).and_then(|s| s.parse().ok());
+            }
+            "--record" => {
+                i += 1;
+                args.record = argv.get(i).cloned();
+            }
+            "--replay" => {
+                i += 1;
+                args.replay = argv.get(i).cloned();
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    args
+}
+
 fn main() -> std::io::Result<()> {
     let mut sc = stdout();
     enable_raw_mode()?;
@@ -212,73 +638,126 @@ fn main() -> std::io::Result<()> {
     sc.execute(Hide)?;
 
     let slowness = 100;
-    let mut world = World::new(maxc, maxl);
+    let args = parse_args();
+
+    // a replay file's first line is "seed:<n>", the rest is one key token per tick
+    let replay_lines = args.replay.as_ref().map(|path| {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    });
+
+    let seed = match &replay_lines {
+        Some(lines) => lines[0].strip_prefix("seed:").unwrap().parse().unwrap(),
+        None => args.seed.unwrap_or_else(|| {
+            time::SystemTime::now()
+                .duration_since(time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        }),
+    };
+    let mut replay_ticks = replay_lines.map(|lines| lines.into_iter().skip(1));
 
-    while world.status == PlayerStatus::Alive {
-        if poll(Duration::from_millis(10))? {
+    let mut record_file = args
+        .record
+        .as_ref()
+        .map(|path| std::fs::File::create(path).unwrap());
+    if let Some(file) = &mut record_file {
+        writeln!(file, "seed:{}", seed)?;
+    }
+
+    let mut world = World::new(maxc, maxl, seed);
+
+    while world.status != PlayerStatus::Dead {
+        let pressed = if let Some(ticks) = &mut replay_ticks {
+            ticks.next().and_then(|token| token_to_key_code(&token))
+        } else if matches!(world.status, PlayerStatus::Alive | PlayerStatus::Paused)
+            && poll(Duration::from_millis(10))?
+        {
             let key = read().unwrap();
             while poll(Duration::from_millis(0)).unwrap() {
                 let _ = read();
             }
             match key {
-                Event::Key(event) => {
-                    if event.kind == KeyEventKind::Press {
-                        match event.code {
-                            KeyCode::Char('q') => break,
-                            KeyCode::Char('w') => {
-                                if world.player_location.l > 1 {
-                                    world.player_location.l -= 1
-                                }
-                            }
-                            KeyCode::Char('s') => {
-                                if world.player_location.l < maxl - 1 {
-                                    world.player_location.l += 1
-                                }
-                            }
-                            KeyCode::Char('d') => {
-                                if world.player_location.c < maxc - 1 {
-                                    world.player_location.c += 1
-                                }
-                            }
-                            KeyCode::Char('a') => {
-                                if world.player_location.c > 1 {
-                                    world.player_location.c -= 1
-                                }
-                            }
-                            KeyCode::Up => {
-                                if world.player_location.l > 1 {
-                                    world.player_location.l -= 1
-                                }
-                            }
-                            KeyCode::Down => {
-                                if world.player_location.l < maxl - 1 {
-                                    world.player_location.l += 1
-                                }
-                            }
-                            KeyCode::Left => {
-                                if world.player_location.c > 1 {
-                                    world.player_location.c -= 1
-                                }
-                            }
-                            KeyCode::Right => {
-                                if world.player_location.c < maxc - 1 {
-                                    world.player_location.c += 1
-                                }
-                            }
-                            KeyCode::Char(' ') => {
-                                if world.bullet.len() == 0 {
-                                    world.bullet.push(Bullet::new(&world))
-                                }
-                            }
-                            _ => {}
-                        }
+                Event::Key(event) if event.kind == KeyEventKind::Press => Some(event.code),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if let Some(file) = &mut record_file {
+            let token = pressed
+                .and_then(key_code_to_token)
+                .unwrap_or_else(|| "-".to_string());
+            writeln!(file, "{}", token)?;
+        }
+
+        match pressed {
+            Some(KeyCode::Char('q')) => break,
+            Some(KeyCode::Char('p')) => {
+                world.status = match world.status {
+                    PlayerStatus::Alive => PlayerStatus::Paused,
+                    PlayerStatus::Paused => PlayerStatus::Alive,
+                    other => other,
+                };
+            }
+            Some(code) if world.status == PlayerStatus::Alive => match code {
+                KeyCode::Char('w') => {
+                    if world.player_location.l > 1 {
+                        world.player_location.l -= 1
+                    }
+                }
+                KeyCode::Char('s') => {
+                    if world.player_location.l < maxl - 1 {
+                        world.player_location.l += 1
+                    }
+                }
+                KeyCode::Char('d') => {
+                    if world.player_location.c < maxc - 1 {
+                        world.player_location.c += 1
+                    }
+                }
+                KeyCode::Char('a') => {
+                    if world.player_location.c > 1 {
+                        world.player_location.c -= 1
+                    }
+                }
+                KeyCode::Up => {
+                    if world.player_location.l > 1 {
+                        world.player_location.l -= 1
+                    }
+                }
+                KeyCode::Down => {
+                    if world.player_location.l < maxl - 1 {
+                        world.player_location.l += 1
+                    }
+                }
+                KeyCode::Left => {
+                    if world.player_location.c > 1 {
+                        world.player_location.c -= 1
+                    }
+                }
+                KeyCode::Right => {
+                    if world.player_location.c < maxc - 1 {
+                        world.player_location.c += 1
+                    }
+                }
+                KeyCode::Char(' ') => {
+                    if world.bullet.len() == 0 {
+                        world.bullet.push(Bullet::new(&world))
                     }
                 }
                 _ => {}
-            }
+            },
+            _ => {}
         }
 
-        physics(&mut world);
+        if world.status != PlayerStatus::Paused {
+            physics(&mut world);
+        }
 
         draw(&sc, &world)?;
 
@@ -293,3 +772,37 @@ fn main() -> std::io::Result<()> {
     disable_raw_mode()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a fixed seed plus no input is a pure function of physics() alone, so the tick
+    // on which the player dies is deterministic; this guards against the seeded RNG
+    // drifting out of sync with this expectation as physics() changes
+    #[test]
+    fn fixed_seed_with_no_input_dies_on_a_known_tick() {
+        let mut world = World::new(80, 24, 42);
+        let mut tick = 0;
+        while world.status != PlayerStatus::Dead {
+            physics(&mut world);
+            tick += 1;
+            assert!(tick < 10_000, "player never died within a reasonable run");
+        }
+
+        assert_eq!(tick, 145);
+    }
+
+    // this seed used to panic within ~51 ticks on a narrowed river before the
+    // next_left/next_right wander and enemy-spawn range were made overflow-safe
+    #[test]
+    fn seed_51_survives_many_ticks_without_panicking() {
+        let mut world = World::new(80, 24, 51);
+        for _ in 0..2000 {
+            if world.status == PlayerStatus::Dead {
+                break;
+            }
+            physics(&mut world);
+        }
+    }
+}